@@ -1,7 +1,10 @@
 use chrono::prelude::*;
+use chrono::FixedOffset;
+use chrono_tz::{OffsetComponents, OffsetName, Tz};
 use polars::prelude::*;
 use rustler::resource::ResourceArc;
 use rustler::{Atom, Encoder, Env, NifStruct, NifUntaggedEnum, Term};
+use std::collections::HashMap;
 use std::convert::TryInto;
 use std::sync::RwLock;
 
@@ -109,31 +112,48 @@ pub struct ExDateTime {
     pub microsecond: (u32, u32),
 }
 
-impl From<i64> for ExDateTime {
-    fn from(ms: i64) -> Self {
-        let sign = ms.signum();
+impl ExDateTime {
+    // `TimeUnit::Nanoseconds` is divided down to microsecond resolution because
+    // Elixir's `Calendar.ISO` microsecond field tops out at 6 digits of precision.
+    fn from_i64(ts: i64, time_unit: &TimeUnit) -> Self {
+        let (unit, nanos_per_unit, precision) = match time_unit {
+            TimeUnit::Milliseconds => (1_000i64, 1_000_000i64, 3),
+            TimeUnit::Microseconds => (1_000_000i64, 1_000i64, 6),
+            TimeUnit::Nanoseconds => (1_000_000_000i64, 1i64, 6),
+        };
+        let sign = ts.signum();
         let seconds = match sign {
-            -1 => ms / 1_000 - 1,
-            _ => ms / 1_000,
+            -1 => ts / unit - 1,
+            _ => ts / unit,
         };
         let remainder = match sign {
-            -1 => 1_000 + ms % 1_000,
-            _ => ms % 1_000,
+            -1 => unit + ts % unit,
+            _ => ts % unit,
         };
-        let nanoseconds = remainder.abs() * 1_000_000;
-        ExDateTime::from(NaiveDateTime::from_timestamp(
+        let nanoseconds = remainder.abs() * nanos_per_unit;
+        let mut dt = ExDateTime::from(NaiveDateTime::from_timestamp(
             seconds,
             nanoseconds.try_into().unwrap(),
-        ))
+        ));
+        dt.microsecond.1 = precision;
+        dt
     }
-}
 
-impl From<ExDateTime> for i64 {
-    fn from(dt: ExDateTime) -> i64 {
-        NaiveDate::from_ymd(dt.year, dt.month, dt.day)
-            .and_hms_micro(dt.hour, dt.minute, dt.second, dt.microsecond.0)
-            .signed_duration_since(NaiveDate::from_ymd(1970, 1, 1).and_hms(0, 0, 0))
-            .num_milliseconds()
+    // The inverse of `from_i64`: reconstructs the `i64` timestamp in whichever
+    // `TimeUnit` the caller asks for, so a value read out at one precision can
+    // be written back at that same precision instead of always widening to a
+    // fixed unit. For `TimeUnit::Nanoseconds` the digits below a microsecond,
+    // which `from_i64` does not keep, come back as zeros.
+    fn to_i64(&self, time_unit: &TimeUnit) -> i64 {
+        let duration = NaiveDate::from_ymd(self.year, self.month, self.day)
+            .and_hms_micro(self.hour, self.minute, self.second, self.microsecond.0)
+            .signed_duration_since(NaiveDate::from_ymd(1970, 1, 1).and_hms(0, 0, 0));
+
+        match time_unit {
+            TimeUnit::Milliseconds => duration.num_milliseconds(),
+            TimeUnit::Microseconds => duration.num_microseconds().unwrap(),
+            TimeUnit::Nanoseconds => duration.num_nanoseconds().unwrap(),
+        }
     }
 }
 
@@ -163,6 +183,158 @@ impl From<NaiveDateTime> for ExDateTime {
     }
 }
 
+// Pairs a decoded `NaiveDateTime` shape with the originating column's
+// `TimeUnit` (as an atom, mirroring `ExDuration::precision`), so a value
+// round-tripped back into Polars writes at the same precision it was read
+// at instead of always widening to a fixed unit. Encodes/decodes as a plain
+// `{datetime, time_unit}` tuple rather than a NifStruct, since `ExDateTime`
+// itself must stay a faithful `NaiveDateTime` mirror.
+#[derive(Clone, Debug)]
+pub struct ExDatetimePrecision {
+    pub datetime: ExDateTime,
+    pub time_unit: Atom,
+}
+
+impl Encoder for ExDatetimePrecision {
+    fn encode<'b>(&self, env: Env<'b>) -> Term<'b> {
+        (self.datetime, self.time_unit).encode(env)
+    }
+}
+
+impl<'a> rustler::Decoder<'a> for ExDatetimePrecision {
+    fn decode(term: Term<'a>) -> rustler::NifResult<Self> {
+        let (datetime, time_unit): (ExDateTime, Atom) = term.decode()?;
+        Ok(ExDatetimePrecision {
+            datetime,
+            time_unit,
+        })
+    }
+}
+
+#[derive(NifStruct, Clone, Debug)]
+#[module = "Elixir.DateTime"]
+pub struct ExDateTimeZoned {
+    pub calendar: Atom,
+    pub day: u32,
+    pub month: u32,
+    pub year: i32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+    pub microsecond: (u32, u32),
+    pub time_zone: String,
+    pub zone_abbr: String,
+    pub utc_offset: i32,
+    pub std_offset: i32,
+}
+
+// Polars' `Datetime(_, Some(tz))` accepts both IANA zone names
+// ("America/New_York") and fixed UTC offsets ("+02:00"), so a plain
+// `tz.parse::<Tz>()` isn't enough to cover every value it can hand us.
+fn parse_fixed_offset(tz: &str) -> Option<FixedOffset> {
+    let (sign, digits) = match tz.as_bytes().first()? {
+        b'+' => (1, &tz[1..]),
+        b'-' => (-1, &tz[1..]),
+        _ => return None,
+    };
+    let digits: String = digits.chars().filter(|c| *c != ':').collect();
+    if digits.len() != 4 {
+        return None;
+    }
+    let hours: i32 = digits[0..2].parse().ok()?;
+    let minutes: i32 = digits[2..4].parse().ok()?;
+
+    FixedOffset::east_opt(sign * (hours * 3_600 + minutes * 60))
+}
+
+impl ExDateTimeZoned {
+    fn from_i64(ts: i64, time_unit: &TimeUnit, tz: &str) -> Self {
+        let naive = ExDateTime::from_i64(ts, time_unit);
+        let utc_ndt = NaiveDateTime::from(naive);
+
+        if let Ok(iana_tz) = tz.parse::<Tz>() {
+            let zoned = Utc.from_utc_datetime(&utc_ndt).with_timezone(&iana_tz);
+
+            return ExDateTimeZoned {
+                calendar: atoms::calendar(),
+                day: zoned.day(),
+                month: zoned.month(),
+                year: zoned.year(),
+                hour: zoned.hour(),
+                minute: zoned.minute(),
+                second: zoned.second(),
+                microsecond: naive.microsecond,
+                time_zone: iana_tz.name().to_string(),
+                zone_abbr: zoned.offset().abbreviation().to_string(),
+                utc_offset: zoned.offset().base_utc_offset().num_seconds() as i32,
+                std_offset: zoned.offset().dst_offset().num_seconds() as i32,
+            };
+        }
+
+        // Not an IANA zone name chrono-tz recognizes — fall back to treating
+        // `tz` as a fixed UTC offset instead of panicking on otherwise-valid
+        // input. A string matching neither form degrades to UTC rather than
+        // crashing the NIF.
+        let offset = parse_fixed_offset(tz).unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+        let zoned = offset.from_utc_datetime(&utc_ndt);
+
+        ExDateTimeZoned {
+            calendar: atoms::calendar(),
+            day: zoned.day(),
+            month: zoned.month(),
+            year: zoned.year(),
+            hour: zoned.hour(),
+            minute: zoned.minute(),
+            second: zoned.second(),
+            microsecond: naive.microsecond,
+            time_zone: tz.to_string(),
+            zone_abbr: tz.to_string(),
+            utc_offset: offset.local_minus_utc(),
+            std_offset: 0,
+        }
+    }
+}
+
+#[derive(NifStruct, Copy, Clone, Debug)]
+#[module = "Time"]
+pub struct ExTime {
+    pub calendar: Atom,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+    pub microsecond: (u32, u32),
+}
+
+impl From<i64> for ExTime {
+    fn from(ns_since_midnight: i64) -> Self {
+        let seconds_of_day = ns_since_midnight / 1_000_000_000;
+        let subsec_nanos = ns_since_midnight % 1_000_000_000;
+
+        ExTime {
+            calendar: atoms::calendar(),
+            hour: (seconds_of_day / 3_600) as u32,
+            minute: ((seconds_of_day / 60) % 60) as u32,
+            second: (seconds_of_day % 60) as u32,
+            microsecond: ((subsec_nanos / 1_000) as u32, 6),
+        }
+    }
+}
+
+impl From<ExTime> for i64 {
+    fn from(t: ExTime) -> i64 {
+        let seconds_of_day =
+            i64::from(t.hour) * 3_600 + i64::from(t.minute) * 60 + i64::from(t.second);
+        seconds_of_day * 1_000_000_000 + i64::from(t.microsecond.0) * 1_000
+    }
+}
+
+#[derive(NifStruct, Copy, Clone, Debug)]
+#[module = "Explorer.Duration"]
+pub struct ExDuration {
+    pub value: i64,
+    pub precision: Atom,
+}
+
 fn encode_date_series<'b>(s: &Series, env: Env<'b>) -> Term<'b> {
     s.date()
         .unwrap()
@@ -173,14 +345,70 @@ fn encode_date_series<'b>(s: &Series, env: Env<'b>) -> Term<'b> {
 }
 
 fn encode_datetime_series<'b>(s: &Series, env: Env<'b>) -> Term<'b> {
-    s.datetime()
+    match s.dtype() {
+        DataType::Datetime(time_unit, None) => s
+            .datetime()
+            .unwrap()
+            .into_iter()
+            .map(|d| d.map(|ts| ExDateTime::from_i64(ts, time_unit)))
+            .collect::<Vec<Option<ExDateTime>>>()
+            .encode(env),
+        DataType::Datetime(time_unit, Some(tz)) => s
+            .datetime()
+            .unwrap()
+            .into_iter()
+            .map(|d| d.map(|ts| ExDateTimeZoned::from_i64(ts, time_unit, tz)))
+            .collect::<Vec<Option<ExDateTimeZoned>>>()
+            .encode(env),
+        dt => panic!("expected a datetime series, got {:?}", dt),
+    }
+}
+
+fn encode_time_series<'b>(s: &Series, env: Env<'b>) -> Term<'b> {
+    s.time()
         .unwrap()
         .into_iter()
-        .map(|d| d.map(ExDateTime::from))
-        .collect::<Vec<Option<ExDateTime>>>()
+        .map(|t| t.map(ExTime::from))
+        .collect::<Vec<Option<ExTime>>>()
         .encode(env)
 }
 
+fn time_unit_atom(time_unit: &TimeUnit) -> Atom {
+    match time_unit {
+        TimeUnit::Milliseconds => atoms::millisecond(),
+        TimeUnit::Microseconds => atoms::microsecond(),
+        TimeUnit::Nanoseconds => atoms::nanosecond(),
+    }
+}
+
+fn time_unit_from_atom(atom: Atom) -> TimeUnit {
+    if atom == atoms::millisecond() {
+        TimeUnit::Milliseconds
+    } else if atom == atoms::nanosecond() {
+        TimeUnit::Nanoseconds
+    } else if atom == atoms::microsecond() {
+        TimeUnit::Microseconds
+    } else {
+        panic!("unrecognized time unit atom: {:?}", atom)
+    }
+}
+
+fn encode_duration_series<'b>(s: &Series, env: Env<'b>) -> Term<'b> {
+    match s.dtype() {
+        DataType::Duration(time_unit) => {
+            let precision = time_unit_atom(time_unit);
+
+            s.duration()
+                .unwrap()
+                .into_iter()
+                .map(|d| d.map(|value| ExDuration { value, precision }))
+                .collect::<Vec<Option<ExDuration>>>()
+                .encode(env)
+        }
+        dt => panic!("expected a duration series, got {:?}", dt),
+    }
+}
+
 macro_rules! encode {
     ($s:ident, $env:ident, $convert_function:ident, $out_type:ty) => {
         $s.$convert_function()
@@ -200,45 +428,84 @@ macro_rules! encode {
     };
 }
 
-macro_rules! encode_list {
-    ($s:ident, $env:ident, $convert_function:ident, $out_type:ty) => {
-        $s.list()
-            .unwrap()
-            .into_iter()
-            .map(|item| item)
-            .collect::<Vec<Option<Series>>>()
-            .iter()
-            .map(|item| {
-                item.clone()
-                    .unwrap()
-                    .$convert_function()
-                    .unwrap()
-                    .into_iter()
-                    .map(|item| item)
-                    .collect::<Vec<Option<$out_type>>>()
-            })
-            .collect::<Vec<Vec<Option<$out_type>>>>()
-            .encode($env)
-    };
+// Each list element is dispatched back through `encode_series`, so nested
+// lists (and lists of structs) recurse through this same match arm.
+fn encode_list_series<'b>(s: &Series, env: Env<'b>) -> Term<'b> {
+    s.list()
+        .unwrap()
+        .into_iter()
+        .map(|item| item.map(|inner| encode_series(&inner, env)))
+        .collect::<Vec<Option<Term<'b>>>>()
+        .encode(env)
+}
+
+fn encode_struct_series<'b>(s: &Series, env: Env<'b>) -> Term<'b> {
+    let ca = s.struct_().unwrap();
+    let fields = ca.fields();
+    let null_mask = s.is_null();
+
+    // Encode each field through `encode_series` (not `ExAnyValue`) so that
+    // struct fields which are themselves List/Struct recurse through the
+    // same dispatch the top-level series uses, instead of a parallel path
+    // that can't represent every dtype.
+    let field_columns: Vec<(&str, Vec<Term<'b>>)> = fields
+        .iter()
+        .map(|field| {
+            let column = encode_series(field, env)
+                .decode::<Vec<Term<'b>>>()
+                .expect("struct field series should encode to a list term");
+            (field.name(), column)
+        })
+        .collect();
+
+    (0..s.len())
+        .map(|idx| {
+            if null_mask.get(idx).unwrap_or(false) {
+                return None;
+            }
+
+            let row: HashMap<&str, Term<'b>> = field_columns
+                .iter()
+                .map(|(name, column)| (*name, column[idx]))
+                .collect();
+
+            Some(row)
+        })
+        .collect::<Vec<Option<HashMap<&str, Term<'b>>>>>()
+        .encode(env)
+}
+
+fn encode_series<'b>(s: &Series, env: Env<'b>) -> Term<'b> {
+    match s.dtype() {
+        DataType::Boolean => encode!(s, env, bool),
+        DataType::Utf8 => encode!(s, env, utf8, &str),
+        // The BEAM has no fixed-width integer or single-precision float types, so every
+        // signed/unsigned integer width below widens to an Elixir integer and `Float32`
+        // widens to a double on the Elixir side. The Rust-side dtype stays exact so a
+        // round trip back into Polars (via `ExAnyValue`) preserves the original width.
+        DataType::Int8 => encode!(s, env, i8),
+        DataType::Int16 => encode!(s, env, i16),
+        DataType::Int32 => encode!(s, env, i32),
+        DataType::Int64 => encode!(s, env, i64),
+        DataType::UInt8 => encode!(s, env, u8),
+        DataType::UInt16 => encode!(s, env, u16),
+        DataType::UInt32 => encode!(s, env, u32),
+        DataType::UInt64 => encode!(s, env, u64),
+        DataType::Float32 => encode!(s, env, f32),
+        DataType::Float64 => encode!(s, env, f64),
+        DataType::Date => encode_date_series(s, env),
+        DataType::Datetime(..) => encode_datetime_series(s, env),
+        DataType::Time => encode_time_series(s, env),
+        DataType::Duration(_) => encode_duration_series(s, env),
+        DataType::Struct(_) => encode_struct_series(s, env),
+        DataType::List(_) => encode_list_series(s, env),
+        dt => panic!("to_list/1 not implemented for {:?}", dt),
+    }
 }
 
 impl<'a> Encoder for ExSeriesRef {
     fn encode<'b>(&self, env: Env<'b>) -> Term<'b> {
-        let s = &self.0;
-        match s.dtype() {
-            DataType::Boolean => encode!(s, env, bool),
-            DataType::Utf8 => encode!(s, env, utf8, &str),
-            DataType::Int32 => encode!(s, env, i32),
-            DataType::Int64 => encode!(s, env, i64),
-            DataType::UInt32 => encode!(s, env, u32),
-            DataType::Float64 => encode!(s, env, f64),
-            DataType::Date => encode_date_series(s, env),
-            DataType::Datetime(TimeUnit::Milliseconds, None) => encode_datetime_series(s, env),
-            DataType::List(t) if t as &DataType == &DataType::UInt32 => {
-                encode_list!(s, env, u32, u32)
-            }
-            dt => panic!("to_list/1 not implemented for {:?}", dt),
-        }
+        encode_series(&self.0, env)
     }
 }
 
@@ -246,12 +513,22 @@ impl<'a> Encoder for ExSeriesRef {
 pub enum ExAnyValue {
     Boolean(bool),
     Utf8(String),
+    Int8(i8),
+    Int16(i16),
     Int32(i32),
     Int64(i64),
+    UInt8(u8),
+    UInt16(u16),
     UInt32(u32),
+    UInt64(u64),
+    Float32(f32),
     Float64(f64),
-    Datetime(ExDateTime),
+    Datetime(ExDatetimePrecision),
+    DatetimeZoned(ExDateTimeZoned),
     Date(ExDate),
+    Time(ExTime),
+    Duration(ExDuration),
+    Struct(Vec<(String, ExAnyValue)>),
 }
 
 impl From<ExAnyValue> for AnyValue<'_> {
@@ -259,14 +536,39 @@ impl From<ExAnyValue> for AnyValue<'_> {
         let value = match val {
             ExAnyValue::Boolean(x) => AnyValue::Boolean(x),
             ExAnyValue::Utf8(x) => AnyValue::Utf8Owned(x),
+            ExAnyValue::Int8(x) => AnyValue::Int8(x),
+            ExAnyValue::Int16(x) => AnyValue::Int16(x),
             ExAnyValue::Int32(x) => AnyValue::Int32(x),
             ExAnyValue::Int64(x) => AnyValue::Int64(x),
+            ExAnyValue::UInt8(x) => AnyValue::UInt8(x),
+            ExAnyValue::UInt16(x) => AnyValue::UInt16(x),
             ExAnyValue::UInt32(x) => AnyValue::UInt32(x),
+            ExAnyValue::UInt64(x) => AnyValue::UInt64(x),
+            ExAnyValue::Float32(x) => AnyValue::Float32(x),
             ExAnyValue::Float64(x) => AnyValue::Float64(x),
             ExAnyValue::Datetime(x) => {
-                AnyValue::Datetime(i64::from(x), TimeUnit::Milliseconds, &None)
+                let time_unit = time_unit_from_atom(x.time_unit);
+                AnyValue::Datetime(x.datetime.to_i64(&time_unit), time_unit, &None)
+            }
+            ExAnyValue::DatetimeZoned(_) => {
+                panic!("writing a time zone-aware datetime back into a series is not supported")
             }
             ExAnyValue::Date(x) => AnyValue::Date(i32::from(x)),
+            ExAnyValue::Time(x) => AnyValue::Time(i64::from(x)),
+            ExAnyValue::Duration(x) => {
+                AnyValue::Duration(x.value, time_unit_from_atom(x.precision))
+            }
+            ExAnyValue::Struct(entries) => {
+                let (fields, values): (Vec<Field>, Vec<AnyValue>) = entries
+                    .into_iter()
+                    .map(|(name, v)| {
+                        let value = AnyValue::from(v);
+                        (Field::new(&name, value.dtype()), value)
+                    })
+                    .unzip();
+
+                AnyValue::StructOwned(Box::new((values, fields)))
+            }
         };
         value
     }
@@ -278,13 +580,308 @@ impl From<AnyValue<'_>> for ExAnyValue {
             AnyValue::Boolean(x) => ExAnyValue::Boolean(x),
             AnyValue::Utf8(x) => ExAnyValue::Utf8(x.to_string()),
             AnyValue::Utf8Owned(x) => ExAnyValue::Utf8(x),
+            AnyValue::Int8(x) => ExAnyValue::Int8(x),
+            AnyValue::Int16(x) => ExAnyValue::Int16(x),
             AnyValue::Int32(x) => ExAnyValue::Int32(x),
             AnyValue::Int64(x) => ExAnyValue::Int64(x),
+            AnyValue::UInt8(x) => ExAnyValue::UInt8(x),
+            AnyValue::UInt16(x) => ExAnyValue::UInt16(x),
             AnyValue::UInt32(x) => ExAnyValue::UInt32(x),
+            AnyValue::UInt64(x) => ExAnyValue::UInt64(x),
+            AnyValue::Float32(x) => ExAnyValue::Float32(x),
             AnyValue::Float64(x) => ExAnyValue::Float64(x),
-            AnyValue::Datetime(x, ..) => ExAnyValue::Datetime(ExDateTime::from(x)),
+            AnyValue::Datetime(x, time_unit, None) => ExAnyValue::Datetime(ExDatetimePrecision {
+                datetime: ExDateTime::from_i64(x, &time_unit),
+                time_unit: time_unit_atom(&time_unit),
+            }),
+            AnyValue::Datetime(x, time_unit, Some(tz)) => {
+                ExAnyValue::DatetimeZoned(ExDateTimeZoned::from_i64(x, &time_unit, tz))
+            }
             AnyValue::Date(x) => ExAnyValue::Date(ExDate::from(x)),
+            AnyValue::Time(x) => ExAnyValue::Time(ExTime::from(x)),
+            AnyValue::Duration(x, time_unit) => ExAnyValue::Duration(ExDuration {
+                value: x,
+                precision: time_unit_atom(&time_unit),
+            }),
+            AnyValue::Struct(idx, arr, fields) => ExAnyValue::Struct(
+                fields
+                    .iter()
+                    .zip(arr.values().iter())
+                    .map(|(field, values)| {
+                        let field_series = Series::try_from((field.name(), values.clone()))
+                            .expect("struct field array should convert to a Series");
+                        (
+                            field.name().to_string(),
+                            ExAnyValue::from(field_series.get(idx)),
+                        )
+                    })
+                    .collect(),
+            ),
+            AnyValue::StructOwned(data) => {
+                let (values, fields) = *data;
+                ExAnyValue::Struct(
+                    fields
+                        .into_iter()
+                        .zip(values.into_iter())
+                        .map(|(field, value)| (field.name().to_string(), ExAnyValue::from(value)))
+                        .collect(),
+                )
+            }
             _ => panic!("unsupported datatype for {:?}", val),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rustler::env::OwnedEnv;
+
+    #[test]
+    fn datetime_round_trip_preserves_precision() {
+        let nanos = 1_700_000_123_456_789i64;
+
+        for (time_unit, ts) in [
+            (TimeUnit::Milliseconds, nanos / 1_000_000),
+            (TimeUnit::Microseconds, nanos / 1_000),
+            (TimeUnit::Nanoseconds, nanos),
+        ] {
+            let dt = ExDateTime::from_i64(ts, &time_unit);
+            assert_eq!(dt.to_i64(&time_unit), ts);
+        }
+    }
+
+    #[test]
+    fn datetime_round_trip_handles_negative_timestamps_with_subsecond_remainder() {
+        // -1 microsecond is 1969-12-31 23:59:59.999999, not truncated toward zero.
+        let dt = ExDateTime::from_i64(-1, &TimeUnit::Microseconds);
+
+        assert_eq!((dt.year, dt.month, dt.day), (1969, 12, 31));
+        assert_eq!((dt.hour, dt.minute, dt.second), (23, 59, 59));
+        assert_eq!(dt.microsecond.0, 999_999);
+        assert_eq!(dt.to_i64(&TimeUnit::Microseconds), -1);
+    }
+
+    #[test]
+    fn any_value_datetime_write_back_preserves_source_time_unit() {
+        let ts = 1_700_000_123_456i64;
+
+        for time_unit in [
+            TimeUnit::Milliseconds,
+            TimeUnit::Microseconds,
+            TimeUnit::Nanoseconds,
+        ] {
+            let any_value = ExAnyValue::Datetime(ExDatetimePrecision {
+                datetime: ExDateTime::from_i64(ts, &time_unit),
+                time_unit: time_unit_atom(&time_unit),
+            });
+
+            match AnyValue::from(any_value) {
+                AnyValue::Datetime(written_ts, written_unit, None) => {
+                    // Sub-microsecond digits of a nanosecond value are not kept.
+                    let expected = match time_unit {
+                        TimeUnit::Nanoseconds => ts / 1_000 * 1_000,
+                        _ => ts,
+                    };
+                    assert_eq!(written_unit, time_unit);
+                    assert_eq!(written_ts, expected);
+                }
+                other => panic!("expected AnyValue::Datetime, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn duration_any_value_round_trips_precision() {
+        for time_unit in [
+            TimeUnit::Milliseconds,
+            TimeUnit::Microseconds,
+            TimeUnit::Nanoseconds,
+        ] {
+            let ex = ExDuration {
+                value: -42,
+                precision: time_unit_atom(&time_unit),
+            };
+
+            match AnyValue::from(ExAnyValue::Duration(ex)) {
+                AnyValue::Duration(value, unit) => {
+                    assert_eq!(value, -42);
+                    assert_eq!(unit, time_unit);
+                }
+                other => panic!("expected AnyValue::Duration, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn time_round_trip() {
+        let ns_since_midnight =
+            12 * 3_600 * 1_000_000_000 + 34 * 60 * 1_000_000_000 + 56 * 1_000_000_000 + 789_000;
+
+        let t = ExTime::from(ns_since_midnight);
+
+        assert_eq!((t.hour, t.minute, t.second), (12, 34, 56));
+        assert_eq!(t.microsecond.0, 789);
+        assert_eq!(i64::from(t), ns_since_midnight);
+    }
+
+    #[test]
+    fn parse_fixed_offset_accepts_colon_and_compact_forms() {
+        assert_eq!(
+            parse_fixed_offset("+02:00"),
+            FixedOffset::east_opt(2 * 3_600)
+        );
+        assert_eq!(
+            parse_fixed_offset("-0530"),
+            FixedOffset::east_opt(-(5 * 3_600 + 30 * 60))
+        );
+        assert_eq!(parse_fixed_offset("not-a-tz"), None);
+    }
+
+    #[test]
+    fn time_unit_atom_round_trips() {
+        for time_unit in [
+            TimeUnit::Milliseconds,
+            TimeUnit::Microseconds,
+            TimeUnit::Nanoseconds,
+        ] {
+            assert_eq!(time_unit_from_atom(time_unit_atom(&time_unit)), time_unit);
+        }
+    }
+
+    #[test]
+    fn full_width_numeric_series_round_trip_with_nulls() {
+        let i64s = Series::new("i", &[Some(i64::MIN), None, Some(i64::MAX)]);
+        let u64s = Series::new("u", &[Some(u64::MAX), None]);
+        let f32s = Series::new("f", &[Some(1.5f32), None]);
+
+        let mut owned_env = OwnedEnv::new();
+        owned_env.run(|env| {
+            assert_eq!(
+                encode_series(&i64s, env)
+                    .decode::<Vec<Option<i64>>>()
+                    .unwrap(),
+                vec![Some(i64::MIN), None, Some(i64::MAX)]
+            );
+            assert_eq!(
+                encode_series(&u64s, env)
+                    .decode::<Vec<Option<u64>>>()
+                    .unwrap(),
+                vec![Some(u64::MAX), None]
+            );
+            assert_eq!(
+                encode_series(&f32s, env)
+                    .decode::<Vec<Option<f32>>>()
+                    .unwrap(),
+                vec![Some(1.5f32), None]
+            );
+        });
+    }
+
+    #[test]
+    fn list_series_round_trips_null_elements() {
+        let mut builder =
+            ListPrimitiveChunkedBuilder::<Int32Type>::new("xs", 2, 6, DataType::Int32);
+        builder.append_slice(Some(&[1, 2, 3]));
+        builder.append_null();
+        let s = builder.finish().into_series();
+
+        let mut owned_env = OwnedEnv::new();
+        owned_env.run(|env| {
+            let decoded: Vec<Option<Vec<Option<i32>>>> = encode_series(&s, env).decode().unwrap();
+
+            assert_eq!(decoded, vec![Some(vec![Some(1), Some(2), Some(3)]), None]);
+        });
+    }
+
+    #[test]
+    fn struct_series_round_trips_list_fields_and_null_leaves() {
+        // Regression test: struct fields used to be encoded through `ExAnyValue`,
+        // which has no `List` variant and panicked on a struct containing one.
+        let mut builder =
+            ListPrimitiveChunkedBuilder::<Int32Type>::new("tags", 2, 4, DataType::Int32);
+        builder.append_slice(Some(&[1, 2]));
+        builder.append_slice(Some(&[3]));
+        let tags = builder.finish().into_series();
+
+        let names = Series::new("name", &[Some("a"), None]);
+
+        let s = StructChunked::new("pair", &[names, tags])
+            .unwrap()
+            .into_series();
+
+        let mut owned_env = OwnedEnv::new();
+        owned_env.run(|env| {
+            let decoded: Vec<Option<HashMap<String, Term>>> =
+                encode_series(&s, env).decode().unwrap();
+
+            let row0 = decoded[0].as_ref().expect("row 0 should not be null");
+            assert_eq!(row0["name"].decode::<String>().unwrap(), "a");
+            assert_eq!(
+                row0["tags"].decode::<Vec<Option<i32>>>().unwrap(),
+                vec![Some(1), Some(2)]
+            );
+
+            let row1 = decoded[1].as_ref().expect("row 1 should not be null");
+            assert!(row1["name"].decode::<Option<String>>().unwrap().is_none());
+            assert_eq!(
+                row1["tags"].decode::<Vec<Option<i32>>>().unwrap(),
+                vec![Some(3)]
+            );
+        });
+    }
+
+    #[test]
+    fn duration_series_round_trip() {
+        let s = Series::new("d", &[Some(1_500i64), None])
+            .cast(&DataType::Duration(TimeUnit::Milliseconds))
+            .unwrap();
+
+        let mut owned_env = OwnedEnv::new();
+        owned_env.run(|env| {
+            let decoded: Vec<Option<ExDuration>> = encode_series(&s, env).decode().unwrap();
+
+            assert_eq!(decoded[0].as_ref().unwrap().value, 1_500);
+            assert!(decoded[1].is_none());
+        });
+    }
+
+    #[test]
+    fn time_series_round_trip() {
+        let ns_since_midnight = 3_723_000_000_000i64; // 01:02:03.000000
+
+        let s = Series::new("t", &[Some(ns_since_midnight), None])
+            .cast(&DataType::Time)
+            .unwrap();
+
+        let mut owned_env = OwnedEnv::new();
+        owned_env.run(|env| {
+            let decoded: Vec<Option<ExTime>> = encode_series(&s, env).decode().unwrap();
+
+            let t = decoded[0].unwrap();
+            assert_eq!((t.hour, t.minute, t.second), (1, 2, 3));
+            assert!(decoded[1].is_none());
+        });
+    }
+
+    #[test]
+    fn zoned_datetime_series_round_trip_with_microsecond_precision() {
+        let ts = 1_700_000_000_123_456i64;
+
+        let s = Series::new("dt", &[Some(ts)])
+            .cast(&DataType::Datetime(
+                TimeUnit::Microseconds,
+                Some("America/New_York".to_string()),
+            ))
+            .unwrap();
+
+        let mut owned_env = OwnedEnv::new();
+        owned_env.run(|env| {
+            let decoded: Vec<Option<ExDateTimeZoned>> = encode_series(&s, env).decode().unwrap();
+
+            let zoned = decoded[0].as_ref().unwrap();
+            assert_eq!(zoned.time_zone, "America/New_York");
+            assert_eq!(zoned.microsecond.0, 123_456);
+        });
+    }
+}